@@ -69,50 +69,226 @@ fn matrix_log_orthogonal(m: &DMatrix<f64>) -> DMatrix<Complex64> {
     &q * &log_t * &q_h
 }
 
-/// Matrix exponential using scaling and squaring method
-fn matrix_exp(m: &DMatrix<Complex64>) -> DMatrix<Complex64> {
-    let _n = m.nrows();
+/// Principal square root of a complex upper-triangular matrix.
+///
+/// Uses the Björck–Hammarling recurrence: diagonal entries are scalar square
+/// roots, off-diagonal entries are filled in one superdiagonal at a time from
+/// the Sylvester relation `R_{ii} R_{ij} + R_{ij} R_{jj} = T_{ij} - Σ_{i<k<j} R_{ik} R_{kj}`.
+fn sqrt_upper_triangular(t: &DMatrix<Complex64>) -> DMatrix<Complex64> {
+    let n = t.nrows();
+    let mut r = DMatrix::<Complex64>::zeros(n, n);
 
-    // Find scaling factor
-    let norm = m.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
-    let k = ((norm / 0.5).ln() / 2.0_f64.ln()).ceil().max(0.0) as i32;
+    for i in 0..n {
+        r[(i, i)] = t[(i, i)].sqrt();
+    }
 
-    // Scale down
-    let scale = 2.0_f64.powi(-k);
-    let a = m * Complex64::new(scale, 0.0);
+    // Fill superdiagonal by superdiagonal (increasing distance from diagonal).
+    for d in 1..n {
+        for i in 0..(n - d) {
+            let j = i + d;
+            let mut s = Complex64::new(0.0, 0.0);
+            for k in (i + 1)..j {
+                s += r[(i, k)] * r[(k, j)];
+            }
+            r[(i, j)] = (t[(i, j)] - s) / (r[(i, i)] + r[(j, j)]);
+        }
+    }
 
-    // Padé approximation of order 6
-    let result = pade_exp(&a);
+    r
+}
 
-    // Square k times
-    let mut result = result;
-    for _ in 0..k {
-        result = &result * &result;
+/// 1-norm (maximum absolute column sum) of a complex matrix.
+fn one_norm(m: &DMatrix<Complex64>) -> f64 {
+    let mut max = 0.0_f64;
+    for j in 0..m.ncols() {
+        let mut col = 0.0_f64;
+        for i in 0..m.nrows() {
+            col += m[(i, j)].norm();
+        }
+        max = max.max(col);
     }
+    max
+}
+
+/// `log(I + X)` for a matrix with small norm via a degree-7 Padé approximant,
+/// evaluated as a 7-point Gauss–Legendre quadrature of `∫₀¹ X (I + τX)⁻¹ dτ`
+/// (the diagonal Padé approximant of the logarithm).
+fn log_pade(x: &DMatrix<Complex64>) -> DMatrix<Complex64> {
+    let n = x.nrows();
+    let identity = DMatrix::<Complex64>::identity(n, n);
 
+    // 7-point Gauss–Legendre nodes/weights mapped from [-1, 1] to [0, 1].
+    const NODES: [f64; 7] = [
+        -0.949_107_912_342_758_5,
+        -0.741_531_185_599_394_4,
+        -0.405_845_151_377_397_2,
+        0.0,
+        0.405_845_151_377_397_2,
+        0.741_531_185_599_394_4,
+        0.949_107_912_342_758_5,
+    ];
+    const WEIGHTS: [f64; 7] = [
+        0.129_484_966_168_869_7,
+        0.279_705_391_489_276_6,
+        0.381_830_050_505_118_9,
+        0.417_959_183_673_469_4,
+        0.381_830_050_505_118_9,
+        0.279_705_391_489_276_6,
+        0.129_484_966_168_869_7,
+    ];
+
+    let mut result = DMatrix::<Complex64>::zeros(n, n);
+    for (node, weight) in NODES.iter().zip(WEIGHTS.iter()) {
+        let tau = 0.5 * (node + 1.0);
+        let w = 0.5 * weight;
+        let denom = &identity + x * Complex64::new(tau, 0.0);
+        let solved = denom
+            .lu()
+            .solve(x)
+            .expect("singular system in Padé logarithm");
+        result += solved * Complex64::new(w, 0.0);
+    }
     result
 }
 
-/// Padé approximation for matrix exponential
-fn pade_exp(a: &DMatrix<Complex64>) -> DMatrix<Complex64> {
-    let n = a.nrows();
+/// Matrix logarithm for a general real matrix with no nonpositive-real
+/// eigenvalues, via inverse scaling-and-squaring on the Schur form.
+///
+/// The matrix is reduced to (complex) Schur form `M = Q T Qᴴ`. The triangular
+/// factor is square-rooted `s` times until `T^(1/2^s)` is within ~0.25 of the
+/// identity in 1-norm, `log(I + X)` is evaluated by a degree-7 Padé
+/// approximant on the reduced matrix, the result is scaled by `2^s`, and mapped
+/// back as `Q · log(T) · Qᴴ`. The result is real for matrices with a real
+/// logarithm, so callers can take the real part. Unlike
+/// [`matrix_log_orthogonal`], this handles nontrivial triangular structure and
+/// stays accurate for near-but-not-exactly-orthogonal inputs.
+fn matrix_logm_general(m: &DMatrix<f64>) -> DMatrix<f64> {
+    let n = m.nrows();
+    let m_complex = m.map(|x| Complex64::new(x, 0.0));
+
+    let schur = m_complex
+        .try_schur(1e-12, 500)
+        .expect("Schur decomposition failed");
+    let (q, mut t) = schur.unpack();
+
+    // Inverse scaling: repeatedly take square roots until T is close to I.
     let identity = DMatrix::<Complex64>::identity(n, n);
+    let mut s = 0u32;
+    while one_norm(&(&t - &identity)) > 0.25 && s < 64 {
+        t = sqrt_upper_triangular(&t);
+        s += 1;
+    }
+
+    // log(I + X) on the reduced triangular factor, undo the scaling.
+    let x = &t - &identity;
+    let log_t = log_pade(&x) * Complex64::new(2.0_f64.powi(s as i32), 0.0);
+
+    let log_m = &q * &log_t * q.adjoint();
+    log_m.map(|z| z.re)
+}
 
-    // Padé approximation of order 6
+/// Matrix exponential via Higham's (2005) scaling-and-squaring scheme.
+///
+/// The scaling factor and Padé degree are chosen from the 1-norm: the smallest
+/// degree `m ∈ {3, 5, 7, 9}` whose theta bound is not exceeded is used directly;
+/// otherwise the matrix is scaled by `2^s` with `s = ⌈log₂(‖A‖₁ / θ₁₃)⌉`,
+/// degree 13 is used, and the result is squared `s` times.
+fn matrix_exp(m: &DMatrix<Complex64>) -> DMatrix<Complex64> {
+    // Theta bounds from Higham, "The Scaling and Squaring Method for the Matrix
+    // Exponential Revisited" (2005), Table 2.3.
+    const THETA_3: f64 = 0.0149559;
+    const THETA_5: f64 = 0.253940;
+    const THETA_7: f64 = 0.950418;
+    const THETA_9: f64 = 2.097848;
+    const THETA_13: f64 = 5.371920;
+
+    let norm = one_norm(m);
+
+    if norm <= THETA_3 {
+        pade_exp(m, &PADE_COEFFS_3)
+    } else if norm <= THETA_5 {
+        pade_exp(m, &PADE_COEFFS_5)
+    } else if norm <= THETA_7 {
+        pade_exp(m, &PADE_COEFFS_7)
+    } else if norm <= THETA_9 {
+        pade_exp(m, &PADE_COEFFS_9)
+    } else {
+        let s = (norm / THETA_13).log2().ceil().max(0.0) as i32;
+        let a = m * Complex64::new(2.0_f64.powi(-s), 0.0);
+        let mut result = pade_exp(&a, &PADE_COEFFS_13);
+        for _ in 0..s {
+            result = &result * &result;
+        }
+        result
+    }
+}
+
+// Padé numerator coefficients `b_k` for the diagonal [m/m] approximant of the
+// matrix exponential (Higham 2005). The denominator is obtained by negating the
+// odd-index coefficients.
+const PADE_COEFFS_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_COEFFS_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_COEFFS_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_COEFFS_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE_COEFFS_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Evaluate the diagonal Padé approximant of `exp(A)` for the given
+/// coefficients using the even/odd split `U = A·Σ b_{2k+1} A^{2k}`,
+/// `V = Σ b_{2k} A^{2k}`, then solving `(V − U)·R = (V + U)`.
+fn pade_exp(a: &DMatrix<Complex64>, coeffs: &[f64]) -> DMatrix<Complex64> {
+    let n = a.nrows();
     let a2 = a * a;
-    let a3 = &a2 * a;
-    let a4 = &a2 * &a2;
-    let a5 = &a4 * a;
-    let a6 = &a3 * &a3;
-
-    let c1 = Complex64::new(1.0, 0.0);
-    let c2 = Complex64::new(0.5, 0.0);
-    let c3 = Complex64::new(1.0 / 6.0, 0.0);
-    let c4 = Complex64::new(1.0 / 24.0, 0.0);
-    let c5 = Complex64::new(1.0 / 120.0, 0.0);
-    let c6 = Complex64::new(1.0 / 720.0, 0.0);
-
-    &identity + a * c1 + &a2 * c2 + &a3 * c3 + &a4 * c4 + &a5 * c5 + &a6 * c6
+
+    // Powers A^0, A^2, A^4, ... up to degree m - 1.
+    let half = coeffs.len() / 2; // (m + 1) / 2 terms in each of U, V
+    let mut even_powers = Vec::with_capacity(half);
+    even_powers.push(DMatrix::<Complex64>::identity(n, n));
+    for k in 1..half {
+        let prev = &even_powers[k - 1];
+        even_powers.push(prev * &a2);
+    }
+
+    let mut u = DMatrix::<Complex64>::zeros(n, n);
+    let mut v = DMatrix::<Complex64>::zeros(n, n);
+    for k in 0..half {
+        u += &even_powers[k] * Complex64::new(coeffs[2 * k + 1], 0.0);
+        v += &even_powers[k] * Complex64::new(coeffs[2 * k], 0.0);
+    }
+    u = a * &u;
+
+    let num = &v + &u;
+    let den = &v - &u;
+    den.lu()
+        .solve(&num)
+        .expect("singular system in Padé exponential")
 }
 
 /// Convert flat array to DMatrix
@@ -131,6 +307,17 @@ fn matrix_to_flat_real(m: &DMatrix<Complex64>) -> Vec<f64> {
     result
 }
 
+/// Convert a real DMatrix to a flat array in row-major order
+fn matrix_real_to_flat(m: &DMatrix<f64>) -> Vec<f64> {
+    let mut result = Vec::with_capacity(m.nrows() * m.ncols());
+    for i in 0..m.nrows() {
+        for j in 0..m.ncols() {
+            result.push(m[(i, j)]);
+        }
+    }
+    result
+}
+
 /// Convert DMatrix to flat array (complex - interleaved real/imag) in row-major order
 fn matrix_to_flat_complex(m: &DMatrix<Complex64>) -> Vec<f64> {
     let mut result = Vec::with_capacity(m.nrows() * m.ncols() * 2);
@@ -143,16 +330,40 @@ fn matrix_to_flat_complex(m: &DMatrix<Complex64>) -> Vec<f64> {
     result
 }
 
-/// Matrix logarithm for orthogonal matrices
-/// 
-/// **Important**: This method only works correctly for orthogonal/unitary matrices.
+/// Matrix logarithm for a general real matrix.
+///
+/// Handles arbitrary real matrices with no nonpositive-real eigenvalues (not
+/// just orthogonal ones) via [`matrix_logm_general`]. The result is returned in
+/// the interleaved-complex flat layout; the imaginary part is zero for inputs
+/// with a real logarithm.
 #[wasm_bindgen]
 pub fn matrix_logm(matrix: &[f64], n: usize) -> Vec<f64> {
     let m = flat_to_matrix(matrix, n);
-    let log_m = matrix_log_orthogonal(&m);
+    let log_m = matrix_logm_general(&m).map(|x| Complex64::new(x, 0.0));
     matrix_to_flat_complex(&log_m)
 }
 
+/// Principal square root of a real matrix, via the Schur form.
+///
+/// Computes `M = Q T Qᴴ`, square-roots the triangular factor with the
+/// Björck–Hammarling recurrence, and reconstructs `Q · √T · Qᴴ`. The result is
+/// returned in the interleaved-complex flat layout; the imaginary part is zero
+/// for matrices with a real principal square root.
+#[wasm_bindgen]
+pub fn matrix_sqrtm(matrix: &[f64], n: usize) -> Vec<f64> {
+    let m = flat_to_matrix(matrix, n);
+    let m_complex = m.map(|x| Complex64::new(x, 0.0));
+
+    let schur = m_complex
+        .try_schur(1e-12, 500)
+        .expect("Schur decomposition failed");
+    let (q, t) = schur.unpack();
+
+    let sqrt_t = sqrt_upper_triangular(&t);
+    let sqrt_m = &q * &sqrt_t * q.adjoint();
+    matrix_to_flat_complex(&sqrt_m)
+}
+
 #[wasm_bindgen]
 pub fn matrix_expm(matrix: &[f64], n: usize) -> Vec<f64> {
     let m = flat_to_matrix(matrix, n);
@@ -161,9 +372,10 @@ pub fn matrix_expm(matrix: &[f64], n: usize) -> Vec<f64> {
     matrix_to_flat_real(&exp_m)
 }
 
-/// Geodesic distance for orthogonal matrices
-/// 
-/// **Important**: This method only works correctly for orthogonal/unitary matrices.
+/// Geodesic distance between two rotations.
+///
+/// Robust to near-but-not-exactly-orthogonal inputs via the general real
+/// logarithm [`matrix_logm_general`].
 #[wasm_bindgen]
 pub fn geodesic_distance(r: &[f64], t: &[f64], n: usize) -> f64 {
     let r_mat = flat_to_matrix(r, n);
@@ -174,15 +386,16 @@ pub fn geodesic_distance(r: &[f64], t: &[f64], n: usize) -> f64 {
     let u = r_transpose * t_mat;
 
     // log(U)
-    let log_u = matrix_log_orthogonal(&u);
+    let log_u = matrix_logm_general(&u);
 
     // Frobenius norm
-    log_u.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt()
+    log_u.iter().map(|x| x * x).sum::<f64>().sqrt()
 }
 
-/// Geodesic interpolation for orthogonal matrices
-/// 
-/// **Important**: This method only works correctly for orthogonal/unitary matrices.
+/// Geodesic interpolation between two rotations.
+///
+/// Robust to near-but-not-exactly-orthogonal inputs via the general real
+/// logarithm [`matrix_logm_general`].
 #[wasm_bindgen]
 pub fn geodesic_interp(a: &[f64], b: &[f64], t: f64, n: usize) -> Vec<f64> {
     let a_mat = flat_to_matrix(a, n);
@@ -193,10 +406,10 @@ pub fn geodesic_interp(a: &[f64], b: &[f64], t: f64, n: usize) -> Vec<f64> {
     let r_rel = a_transpose * b_mat;
 
     // log(R_rel)
-    let log_r = matrix_log_orthogonal(&r_rel);
+    let log_r = matrix_logm_general(&r_rel);
 
     // t * log(R_rel)
-    let scaled_log = log_r * Complex64::new(t, 0.0);
+    let scaled_log = (log_r * t).map(|x| Complex64::new(x, 0.0));
 
     // exp(scaled_log)
     let r_interp = matrix_exp(&scaled_log);
@@ -208,6 +421,178 @@ pub fn geodesic_interp(a: &[f64], b: &[f64], t: f64, n: usize) -> Vec<f64> {
     matrix_to_flat_real(&result)
 }
 
+/// Real Schur decomposition `M = Q T Qᵀ` exposed as a structured object.
+///
+/// `Q` is orthogonal and `T` is upper quasi-triangular (1×1 blocks for real
+/// eigenvalues, 2×2 blocks for complex-conjugate pairs). Exposing the factors
+/// lets callers inspect eigenvalues and detect non-orthogonality. The
+/// module's `matrix_logm`, `matrix_sqrtm` and `matrix_expm` take the matrix
+/// itself and do not accept these factors; `matrix_logm` and `matrix_sqrtm`
+/// compute their own Schur form on every call.
+#[wasm_bindgen]
+pub struct SchurDecomposition {
+    q: Vec<f64>,
+    t: Vec<f64>,
+    eigenvalues: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl SchurDecomposition {
+    /// Orthogonal factor `Q`, row-major.
+    #[wasm_bindgen(getter)]
+    pub fn q(&self) -> Vec<f64> {
+        self.q.clone()
+    }
+
+    /// Quasi-triangular factor `T`, row-major.
+    #[wasm_bindgen(getter)]
+    pub fn t(&self) -> Vec<f64> {
+        self.t.clone()
+    }
+
+    /// Eigenvalues as interleaved real/imag pairs, read off the diagonal and
+    /// 2×2 blocks of `T`.
+    #[wasm_bindgen(getter)]
+    pub fn eigenvalues(&self) -> Vec<f64> {
+        self.eigenvalues.clone()
+    }
+}
+
+/// Compute the real Schur decomposition of a real matrix.
+#[wasm_bindgen]
+pub fn schur_decompose(matrix: &[f64], n: usize) -> SchurDecomposition {
+    let m = flat_to_matrix(matrix, n);
+    let schur = m.try_schur(1e-12, 500).expect("Schur decomposition failed");
+    let (q, t) = schur.unpack();
+
+    // Read eigenvalues off the quasi-triangular factor: a 1×1 block is a real
+    // eigenvalue, a 2×2 block contributes a complex-conjugate pair.
+    let mut eigenvalues = Vec::with_capacity(2 * n);
+    let mut i = 0;
+    while i < n {
+        let is_block = i + 1 < n && t[(i + 1, i)].abs() > 1e-12;
+        if is_block {
+            let a = t[(i, i)];
+            let b = t[(i, i + 1)];
+            let c = t[(i + 1, i)];
+            let d = t[(i + 1, i + 1)];
+            let re = 0.5 * (a + d);
+            let disc = 0.25 * (a - d).powi(2) + b * c;
+            if disc < 0.0 {
+                let im = (-disc).sqrt();
+                eigenvalues.push(re);
+                eigenvalues.push(im);
+                eigenvalues.push(re);
+                eigenvalues.push(-im);
+            } else {
+                let root = disc.sqrt();
+                eigenvalues.push(re + root);
+                eigenvalues.push(0.0);
+                eigenvalues.push(re - root);
+                eigenvalues.push(0.0);
+            }
+            i += 2;
+        } else {
+            eigenvalues.push(t[(i, i)]);
+            eigenvalues.push(0.0);
+            i += 1;
+        }
+    }
+
+    SchurDecomposition {
+        q: matrix_real_to_flat(&q),
+        t: matrix_real_to_flat(&t),
+        eigenvalues,
+    }
+}
+
+/// Karcher (Fréchet) mean of several rotations on SO(n).
+///
+/// Computes the Riemannian barycenter of `count` stacked orthogonal matrices by
+/// gradient descent: starting from the first matrix, repeatedly form the
+/// average tangent vector `Δ = (1/count)·Σ logm(Rᵀ·Mₖ)` and update
+/// `R ← R·expm(Δ)`, stopping when `‖Δ‖_F` falls below a tolerance or a
+/// maximum iteration count is reached. Returns the averaged rotation in
+/// row-major form.
+#[wasm_bindgen]
+pub fn rotation_mean(matrices: &[f64], count: usize, n: usize) -> Vec<f64> {
+    const TOL: f64 = 1e-10;
+    const MAX_ITER: usize = 100;
+
+    let stride = n * n;
+    let mat = |k: usize| flat_to_matrix(&matrices[k * stride..(k + 1) * stride], n);
+
+    // Initialize the estimate to the first matrix.
+    let mut r = mat(0);
+
+    for _ in 0..MAX_ITER {
+        let r_transpose = r.transpose();
+
+        // Average tangent vector in the Lie algebra.
+        let mut delta = DMatrix::<f64>::zeros(n, n);
+        for k in 0..count {
+            let rel = &r_transpose * mat(k);
+            delta += matrix_logm_general(&rel);
+        }
+        delta /= count as f64;
+
+        let norm = delta.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        // R ← R · expm(Δ)
+        let exp_delta = matrix_exp(&delta.map(|x| Complex64::new(x, 0.0))).map(|z| z.re);
+        r *= exp_delta;
+
+        if norm < TOL {
+            break;
+        }
+    }
+
+    matrix_real_to_flat(&r)
+}
+
+/// Batched geodesic distance between many reference/target pairs.
+///
+/// `refs` and `targets` each hold `count` stacked `n×n` matrices in row-major
+/// order; the returned vector holds the `count` distances. Amortizes the
+/// JS↔WASM boundary crossing over the whole batch.
+#[wasm_bindgen]
+pub fn geodesic_distance_batch(refs: &[f64], targets: &[f64], count: usize, n: usize) -> Vec<f64> {
+    let stride = n * n;
+    let mut result = Vec::with_capacity(count);
+    for k in 0..count {
+        let r = &refs[k * stride..(k + 1) * stride];
+        let t = &targets[k * stride..(k + 1) * stride];
+        result.push(geodesic_distance(r, t, n));
+    }
+    result
+}
+
+/// Geodesic path between two rotations sampled at many parameters.
+///
+/// Decomposes `Aᵀ·B` once and reuses `log(Aᵀ·B)` across every sample, only
+/// recomputing `expm(t·log_r)` per `t`. Returns the interpolated matrices
+/// stacked in row-major order, one `n×n` block per entry of `ts`. This is a
+/// substantial throughput win for animation/trajectory use cases.
+#[wasm_bindgen]
+pub fn geodesic_interp_path(a: &[f64], b: &[f64], ts: &[f64], n: usize) -> Vec<f64> {
+    let a_mat = flat_to_matrix(a, n);
+    let b_mat = flat_to_matrix(b, n);
+
+    // log(A^T * B), computed once and reused across all samples.
+    let r_rel = a_mat.transpose() * &b_mat;
+    let log_r = matrix_logm_general(&r_rel);
+    let a_complex = a_mat.map(|x| Complex64::new(x, 0.0));
+
+    let mut result = Vec::with_capacity(ts.len() * n * n);
+    for &t in ts {
+        let scaled_log = (&log_r * t).map(|x| Complex64::new(x, 0.0));
+        let r_interp = matrix_exp(&scaled_log);
+        let interp = &a_complex * r_interp;
+        result.extend(matrix_to_flat_real(&interp));
+    }
+    result
+}
+
 #[wasm_bindgen]
 pub fn init() {
     // Initialize WASM module
@@ -296,4 +681,38 @@ mod tests {
             "z-component should be near 0"
         );
     }
+
+    #[test]
+    fn test_matrix_logm_general_identity() {
+        // log(I) = 0
+        let identity = DMatrix::identity(4, 4);
+        let log_i = matrix_logm_general(&identity);
+        for x in log_i.iter() {
+            assert!(x.abs() < 1e-10, "log(I) element {} expected ~0", x);
+        }
+    }
+
+    #[test]
+    fn test_matrix_logm_general_upper_triangular() {
+        // A non-normal matrix with a nontrivial triangular structure, which the
+        // orthogonal-only routine cannot handle. exp(log(A)) should recover A.
+        let a = DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 0.0, 3.0]);
+        let log_a = matrix_logm_general(&a);
+
+        let log_complex = log_a.map(|x| Complex64::new(x, 0.0));
+        let recovered = matrix_exp(&log_complex);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(
+                    (recovered[(i, j)].re - a[(i, j)]).abs() < 1e-8,
+                    "exp(log(A))[{},{}] = {}, expected {}",
+                    i,
+                    j,
+                    recovered[(i, j)].re,
+                    a[(i, j)]
+                );
+            }
+        }
+    }
 }